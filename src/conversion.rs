@@ -0,0 +1,173 @@
+//! Typed access to the raw string properties stashed in [`crate::memory::MemDevice::extra_props`].
+
+use crate::{Error, Result};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How to interpret a raw SMBIOS property string.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub enum Conversion {
+    /// Returned as-is.
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    /// Parsed as `%Y-%m-%dT%H:%M:%S`, yielding seconds since the Unix epoch.
+    Timestamp,
+    /// Parsed with a caller-supplied `strftime`-style format (`%Y`, `%m`, `%d`,
+    /// `%H`, `%M`, `%S`), yielding seconds since the Unix epoch.
+    TimestampFmt(String),
+}
+
+/// A value produced by applying a [`Conversion`] to a raw property string.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bytes(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    /// Seconds since the Unix epoch.
+    Timestamp(i64),
+}
+
+impl Conversion {
+    fn convert(&self, raw: &str) -> Option<Value> {
+        match self {
+            Conversion::Bytes => Some(Value::Bytes(raw.to_string())),
+            Conversion::Integer => raw.trim().parse().ok().map(Value::Integer),
+            Conversion::Float => raw.trim().parse().ok().map(Value::Float),
+            Conversion::Boolean => parse_bool(raw).map(Value::Boolean),
+            Conversion::Timestamp => {
+                parse_timestamp(raw, "%Y-%m-%dT%H:%M:%S").map(Value::Timestamp)
+            }
+            Conversion::TimestampFmt(fmt) => parse_timestamp(raw, fmt).map(Value::Timestamp),
+        }
+    }
+}
+
+impl crate::memory::MemDevice {
+    /// Looks up `key` in `extra_props` and converts it per `conv`.
+    ///
+    /// # Errors
+    /// Returns [`Error::Missing`] if `key` is not present, or
+    /// [`Error::Conversion`] if the stored value cannot be converted.
+    pub fn get_as(&self, key: &str, conv: Conversion) -> Result<Value> {
+        let raw = self.extra_props.get(key).ok_or(Error::Missing)?;
+        conv.convert(raw).ok_or(Error::Conversion)
+    }
+}
+
+fn parse_bool(raw: &str) -> Option<bool> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "y" => Some(true),
+        "0" | "false" | "no" | "n" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_timestamp(raw: &str, fmt: &str) -> Option<i64> {
+    let mut year = 0_i32;
+    let mut month = 1_u32;
+    let mut day = 1_u32;
+    let mut hour = 0_u32;
+    let mut minute = 0_u32;
+    let mut second = 0_u32;
+
+    let mut value = raw.trim().chars();
+    let mut format = fmt.chars().peekable();
+
+    while let Some(fc) = format.next() {
+        if fc == '%' {
+            let spec = format.next()?;
+            let len = if spec == 'Y' { 4 } else { 2 };
+            let digits: String = value.by_ref().take(len).collect();
+            if digits.len() != len {
+                return None;
+            }
+            let n: i64 = digits.parse().ok()?;
+            match spec {
+                'Y' => year = n as i32,
+                'm' => month = n as u32,
+                'd' => day = n as u32,
+                'H' => hour = n as u32,
+                'M' => minute = n as u32,
+                'S' => second = n as u32,
+                _ => return None,
+            }
+        } else if value.next()? != fc {
+            return None;
+        }
+    }
+
+    if value.next().is_some() {
+        return None;
+    }
+
+    Some(
+        days_from_civil(year, month, day) * 86400
+            + i64::from(hour) * 3600
+            + i64::from(minute) * 60
+            + i64::from(second),
+    )
+}
+
+/// Days since the Unix epoch for a Gregorian calendar date.
+/// <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(y: i32, m: u32, d: u32) -> i64 {
+    let y = i64::from(if m <= 2 { y - 1 } else { y });
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (i64::from(m) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(d) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_integer() {
+        assert_eq!(Conversion::Integer.convert("64"), Some(Value::Integer(64)));
+        assert_eq!(Conversion::Integer.convert("nope"), None);
+    }
+
+    #[test]
+    fn converts_float() {
+        assert_eq!(
+            Conversion::Float.convert("1.5"),
+            Some(Value::Float(1.5))
+        );
+    }
+
+    #[test]
+    fn converts_boolean() {
+        assert_eq!(Conversion::Boolean.convert("yes"), Some(Value::Boolean(true)));
+        assert_eq!(Conversion::Boolean.convert("0"), Some(Value::Boolean(false)));
+        assert_eq!(Conversion::Boolean.convert("maybe"), None);
+    }
+
+    #[test]
+    fn converts_timestamp() {
+        assert_eq!(
+            Conversion::Timestamp.convert("1970-01-01T00:00:00"),
+            Some(Value::Timestamp(0))
+        );
+        assert_eq!(
+            Conversion::Timestamp.convert("2024-01-01T00:00:00"),
+            Some(Value::Timestamp(1_704_067_200))
+        );
+    }
+
+    #[test]
+    fn converts_timestamp_with_custom_format() {
+        assert_eq!(
+            Conversion::TimestampFmt("%m/%d/%Y".to_string()).convert("01/01/1970"),
+            Some(Value::Timestamp(0))
+        );
+    }
+}
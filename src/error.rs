@@ -8,4 +8,8 @@ pub enum Error {
     Missing,
     #[error("Error Parsing Integer")]
     ParseInt(#[from] std::num::ParseIntError),
+    #[error("Error Converting Value")]
+    Conversion,
+    #[error("Malformed SMBIOS table")]
+    Smbios,
 }
@@ -0,0 +1,7 @@
+pub mod conversion;
+pub mod error;
+pub mod memory;
+pub mod size;
+pub mod smbios;
+
+pub use error::{Error, Result};
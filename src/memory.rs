@@ -1,3 +1,4 @@
+use crate::size::parse_size;
 use crate::{Error, Result};
 use std::collections::HashMap;
 use std::path::Path;
@@ -19,6 +20,8 @@ pub struct MemDevice {
     pub frequency: Option<u64>,
     pub form_factor: Option<String>,
     pub mem_type: MemType,
+    /// Installed capacity of this device, in bytes.
+    pub capacity: Option<u64>,
     pub extra_props: HashMap<String, String>,
 }
 
@@ -28,6 +31,13 @@ pub enum MemType {
     Ddr5,
     Ddr4,
     Ddr3,
+    Ddr2,
+    Sdram,
+    Lpddr3,
+    Lpddr4,
+    Lpddr4x,
+    Lpddr5,
+    Lpddr5x,
     Unknown,
     Other(String),
 }
@@ -40,6 +50,13 @@ impl From<String> for MemType {
             "DDR5" => Ddr5,
             "DDR4" => Ddr4,
             "DDR3" => Ddr3,
+            "DDR2" => Ddr2,
+            "SDRAM" => Sdram,
+            "LPDDR3" => Lpddr3,
+            "LPDDR4" => Lpddr4,
+            "LPDDR4X" => Lpddr4x,
+            "LPDDR5" => Lpddr5,
+            "LPDDR5X" => Lpddr5x,
             "Unknown" => Unknown,
             _ => Other(value),
         }
@@ -49,13 +66,37 @@ impl From<String> for MemType {
 impl std::fmt::Display for MemType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
-            MemType::Other(v) => v.to_string(),
-            t => format!("{t:?}"),
+            MemType::Ddr5 => "DDR5",
+            MemType::Ddr4 => "DDR4",
+            MemType::Ddr3 => "DDR3",
+            MemType::Ddr2 => "DDR2",
+            MemType::Sdram => "SDRAM",
+            MemType::Lpddr3 => "LPDDR3",
+            MemType::Lpddr4 => "LPDDR4",
+            MemType::Lpddr4x => "LPDDR4X",
+            MemType::Lpddr5 => "LPDDR5",
+            MemType::Lpddr5x => "LPDDR5X",
+            MemType::Unknown => "Unknown",
+            MemType::Other(v) => v,
         };
         write!(f, "{s}")
     }
 }
 
+impl MemType {
+    /// Returns the DDR generation number this type belongs to, where applicable.
+    #[must_use]
+    pub fn generation(&self) -> Option<u8> {
+        match self {
+            MemType::Ddr2 => Some(2),
+            MemType::Ddr3 | MemType::Lpddr3 => Some(3),
+            MemType::Ddr4 | MemType::Lpddr4 | MemType::Lpddr4x => Some(4),
+            MemType::Ddr5 | MemType::Lpddr5 | MemType::Lpddr5x => Some(5),
+            MemType::Sdram | MemType::Unknown | MemType::Other(_) => None,
+        }
+    }
+}
+
 impl MemDevice {
     /// Returns new `MemDevice`
     ///
@@ -84,24 +125,42 @@ impl From<HashMap<String, String>> for MemDevice {
         let mem_type = extra_props
             .remove("TYPE")
             .map_or(MemType::Unknown, Into::into);
+        let capacity = extra_props.remove("SIZE").and_then(|x| parse_size(&x));
 
         Self {
             manufacturer,
             frequency,
             form_factor,
             mem_type,
+            capacity,
             extra_props,
         }
     }
 }
 
+const SMBIOS_TABLE_PATH: &str = "/sys/firmware/dmi/tables/DMI";
+
 impl Memory {
     /// Return a new memory object.
+    ///
+    /// Tries the udev-decoded DMI properties first, falling back to parsing
+    /// the raw SMBIOS table directly if udev is unavailable or incomplete.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the memory stats cannot be parsed by either
+    /// backend.
+    pub fn new() -> Result<Self> {
+        Self::from_udev().or_else(|_| Self::from_smbios_tables())
+    }
+
+    /// Builds a `Memory` from udev-decoded DMI properties.
+    ///
     /// # Errors
     ///
     /// Will return an error if the memory stats cannot be parsed.
     /// Does not error on failure to obtain smbios information
-    pub fn new() -> Result<Self> {
+    fn from_udev() -> Result<Self> {
         let udev = Device::from_syspath(Path::new("/sys/devices/virtual/dmi/id"))?;
         let props = udev.properties();
         let props_vec: Vec<Entry<'_>> = props.collect();
@@ -133,6 +192,19 @@ impl Memory {
         })
     }
 
+    /// Builds a `Memory` by parsing the raw SMBIOS structure table directly,
+    /// without going through udev.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the table cannot be read or is malformed.
+    pub fn from_smbios_tables() -> Result<Self> {
+        let data = crate::smbios::read_table(Path::new(SMBIOS_TABLE_PATH))?;
+        let devices = crate::smbios::devices_from_table(&data)?;
+
+        Ok(Self { devices })
+    }
+
     pub fn avg_frequency(&self) -> u64 {
         let mut v = Vec::new();
         for dev in &self.devices {
@@ -142,6 +214,25 @@ impl Memory {
         }
         avg_frequency(v)
     }
+
+    /// Sums the installed capacity of every device, in bytes.
+    pub fn total_capacity(&self) -> u64 {
+        let mut v = Vec::new();
+        for dev in &self.devices {
+            if let Some(c) = dev.capacity {
+                v.push(c);
+            }
+        }
+        sum_capacity(v)
+    }
+}
+
+fn sum_capacity(c: Vec<u64>) -> u64 {
+    let mut sum = 0;
+    for cap in c {
+        sum += cap;
+    }
+    sum
 }
 
 fn sum_frequency(f: Vec<u64>) -> u64 {
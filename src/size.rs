@@ -0,0 +1,82 @@
+//! Parsing and formatting of human-readable byte sizes (e.g. `"16 GB"`, `"512M"`).
+
+/// Parses a human-readable size string into a byte count.
+///
+/// Accepts a bare integer (already in bytes), or a number followed by a
+/// `K`/`M`/`G`/`T` suffix, optionally followed by `B` or `iB` (e.g. `"16 GB"`,
+/// `"8192 MB"`, `"512M"`, `"4GiB"`). All suffixes are treated as binary
+/// multiples of 1024, matching how SMBIOS and most VM tooling report memory
+/// sizes.
+pub fn parse_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+
+    if let Ok(bytes) = s.parse::<u64>() {
+        return Some(bytes);
+    }
+
+    let split = s.find(|c: char| c.is_alphabetic())?;
+    let (num, unit) = s.split_at(split);
+    let num: f64 = num.trim().parse().ok()?;
+    let multiplier = match unit.trim().chars().next()?.to_ascii_uppercase() {
+        'K' => 1024_u64,
+        'M' => 1024_u64.pow(2),
+        'G' => 1024_u64.pow(3),
+        'T' => 1024_u64.pow(4),
+        _ => return None,
+    };
+
+    Some((num * multiplier as f64).round() as u64)
+}
+
+/// A byte count that displays as the nearest binary unit (e.g. `16.0 GiB`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByteSize(pub u64);
+
+impl std::fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+        let mut value = self.0 as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            write!(f, "{} {}", self.0, UNITS[unit])
+        } else {
+            write!(f, "{value:.1} {}", UNITS[unit])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_suffixed_sizes() {
+        assert_eq!(parse_size("16 GB"), Some(16 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("8192 MB"), Some(8192 * 1024 * 1024));
+        assert_eq!(parse_size("512M"), Some(512 * 1024 * 1024));
+        assert_eq!(parse_size("4G"), Some(4 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("4GiB"), Some(4 * 1024 * 1024 * 1024));
+    }
+
+    #[test]
+    fn parses_raw_byte_counts() {
+        assert_eq!(parse_size("17179869184"), Some(17_179_869_184));
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(parse_size("not a size"), None);
+    }
+
+    #[test]
+    fn formats_nearest_unit() {
+        assert_eq!(ByteSize(512).to_string(), "512 B");
+        assert_eq!(ByteSize(16 * 1024 * 1024 * 1024).to_string(), "16.0 GiB");
+    }
+}
@@ -0,0 +1,264 @@
+//! Parses raw SMBIOS structure tables (`/sys/firmware/dmi/tables/DMI`), independent of udev.
+
+use crate::memory::{MemDevice, MemType};
+use crate::{Error, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const TYPE_MEMORY_DEVICE: u8 = 17;
+const TYPE_END_OF_TABLE: u8 = 127;
+
+/// Reads the raw SMBIOS structure table from `path`.
+///
+/// # Errors
+/// Returns an error if the table cannot be read.
+pub fn read_table(path: &Path) -> Result<Vec<u8>> {
+    Ok(fs::read(path)?)
+}
+
+/// Parses every Type 17 (Memory Device) structure out of a raw SMBIOS table.
+///
+/// # Errors
+/// Returns [`Error::Smbios`] if the table is truncated or malformed.
+pub fn devices_from_table(data: &[u8]) -> Result<Vec<MemDevice>> {
+    let mut devices = Vec::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        if offset + 4 > data.len() {
+            return Err(Error::Smbios);
+        }
+
+        let smtype = data[offset];
+        let length = data[offset + 1] as usize;
+
+        if length < 4 || offset + length > data.len() {
+            return Err(Error::Smbios);
+        }
+
+        let structure = &data[offset..offset + length];
+        let (strings, next_offset) = read_string_set(data, offset + length)?;
+
+        if smtype == TYPE_END_OF_TABLE {
+            break;
+        }
+
+        if smtype == TYPE_MEMORY_DEVICE {
+            devices.push(parse_type17(structure, &strings));
+        }
+
+        offset = next_offset;
+    }
+
+    Ok(devices)
+}
+
+/// Reads the NUL-terminated string set that follows a structure's formatted
+/// section, returning the strings and the offset of the next structure.
+fn read_string_set(data: &[u8], start: usize) -> Result<(Vec<String>, usize)> {
+    let mut strings = Vec::new();
+    let mut pos = start;
+
+    if data.get(pos).ok_or(Error::Smbios)? == &0 {
+        pos += 1;
+    } else {
+        loop {
+            let str_end = data[pos..]
+                .iter()
+                .position(|&b| b == 0)
+                .map(|p| pos + p)
+                .ok_or(Error::Smbios)?;
+            strings.push(String::from_utf8_lossy(&data[pos..str_end]).into_owned());
+            pos = str_end + 1;
+            if data.get(pos).ok_or(Error::Smbios)? == &0 {
+                break;
+            }
+        }
+    }
+
+    Ok((strings, pos + 1))
+}
+
+fn read_u8(d: &[u8], offset: usize) -> Option<u8> {
+    d.get(offset).copied()
+}
+
+fn read_u16(d: &[u8], offset: usize) -> Option<u16> {
+    (offset + 2 <= d.len()).then(|| u16::from_le_bytes([d[offset], d[offset + 1]]))
+}
+
+fn read_u32(d: &[u8], offset: usize) -> Option<u32> {
+    (offset + 4 <= d.len()).then(|| {
+        u32::from_le_bytes([d[offset], d[offset + 1], d[offset + 2], d[offset + 3]])
+    })
+}
+
+fn read_string(strings: &[String], d: &[u8], offset: usize) -> Option<String> {
+    match read_u8(d, offset)? {
+        0 => None,
+        idx => strings.get(idx as usize - 1).cloned(),
+    }
+}
+
+fn parse_type17(d: &[u8], strings: &[String]) -> MemDevice {
+    let manufacturer = read_string(strings, d, 0x17);
+    let device_locator = read_string(strings, d, 0x10);
+    let bank_locator = read_string(strings, d, 0x11);
+
+    let total_width = read_u16(d, 0x08);
+    let data_width = read_u16(d, 0x0A);
+    let form_factor = read_u8(d, 0x0E).map(|v| form_factor_name(v).to_string());
+    let mem_type = read_u8(d, 0x12).map_or(MemType::Unknown, |v| memory_type_name(v).to_string().into());
+    let frequency = read_u16(d, 0x20).map(u64::from).filter(|&v| v != 0);
+
+    let capacity = match read_u16(d, 0x0C) {
+        None | Some(0) | Some(0xFFFF) => None,
+        Some(0x7FFF) => read_u32(d, 0x1C).map(|mb| u64::from(mb) * 1024 * 1024),
+        Some(v) if v & 0x8000 != 0 => Some(u64::from(v & 0x7FFF) * 1024),
+        Some(v) => Some(u64::from(v) * 1024 * 1024),
+    };
+
+    let mut extra_props = HashMap::new();
+    if let Some(w) = total_width {
+        extra_props.insert("TOTAL_WIDTH".to_string(), w.to_string());
+    }
+    if let Some(w) = data_width {
+        extra_props.insert("DATA_WIDTH".to_string(), w.to_string());
+    }
+    if let Some(loc) = device_locator {
+        extra_props.insert("LOCATOR".to_string(), loc);
+    }
+    if let Some(loc) = bank_locator {
+        extra_props.insert("BANK_LOCATOR".to_string(), loc);
+    }
+
+    MemDevice {
+        manufacturer,
+        frequency,
+        form_factor,
+        mem_type,
+        capacity,
+        extra_props,
+    }
+}
+
+/// Maps the SMBIOS Type 17 "Form Factor" enum (offset `0x0E`) to the same
+/// strings the udev DMI decoder reports.
+fn form_factor_name(code: u8) -> &'static str {
+    match code {
+        0x02 => "Unknown",
+        0x03 => "SIMM",
+        0x04 => "SIP",
+        0x05 => "Chip",
+        0x06 => "DIP",
+        0x07 => "ZIP",
+        0x08 => "Proprietary Card",
+        0x09 => "DIMM",
+        0x0A => "TSOP",
+        0x0B => "Row Of Chips",
+        0x0C => "RIMM",
+        0x0D => "SODIMM",
+        0x0E => "SRIMM",
+        0x0F => "FB-DIMM",
+        0x10 => "Die",
+        _ => "Other",
+    }
+}
+
+/// Maps the SMBIOS Type 17 "Memory Type" enum (offset `0x12`) to the same
+/// strings the udev DMI decoder reports, so [`MemType::from`] yields
+/// identical values regardless of backend.
+fn memory_type_name(code: u8) -> &'static str {
+    match code {
+        0x02 => "Unknown",
+        0x03 => "DRAM",
+        0x0F => "SDRAM",
+        0x12 => "DDR",
+        0x13 => "DDR2",
+        0x18 => "DDR3",
+        0x1A => "DDR4",
+        0x1B => "LPDDR",
+        0x1C => "LPDDR2",
+        0x1D => "LPDDR3",
+        0x1E => "LPDDR4",
+        0x22 => "DDR5",
+        0x23 => "LPDDR5",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type17(total_width: u16, data_width: u16, size: u16, form_factor: u8, mem_type: u8, speed: u16) -> Vec<u8> {
+        let mut s = vec![0u8; 0x22];
+        s[0] = 17; // type
+        s[1] = 0x22; // length
+        s[2..4].copy_from_slice(&0u16.to_le_bytes()); // handle
+        s[0x08..0x0A].copy_from_slice(&total_width.to_le_bytes());
+        s[0x0A..0x0C].copy_from_slice(&data_width.to_le_bytes());
+        s[0x0C..0x0E].copy_from_slice(&size.to_le_bytes());
+        s[0x0E] = form_factor;
+        s[0x10] = 1; // device locator string index
+        s[0x11] = 2; // bank locator string index
+        s[0x12] = mem_type;
+        s[0x17] = 3; // manufacturer string index
+        s[0x20..0x22].copy_from_slice(&speed.to_le_bytes());
+        s.extend_from_slice(b"DIMM0\0BANK0\0Corsair\0\0");
+        s
+    }
+
+    #[test]
+    fn parses_single_type17_structure() {
+        let mut data = type17(64, 64, 0x4000, 0x09, 0x1A, 3200);
+        data.push(TYPE_END_OF_TABLE);
+        data.push(4);
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.extend_from_slice(b"\0\0");
+
+        let devices = devices_from_table(&data).unwrap();
+        assert_eq!(devices.len(), 1);
+
+        let dev = &devices[0];
+        assert_eq!(dev.manufacturer.as_deref(), Some("Corsair"));
+        assert_eq!(dev.form_factor.as_deref(), Some("DIMM"));
+        assert!(matches!(dev.mem_type, MemType::Ddr4));
+        assert_eq!(dev.frequency, Some(3200));
+        assert_eq!(dev.capacity, Some(0x4000 * 1024 * 1024));
+        assert_eq!(dev.extra_props.get("LOCATOR").map(String::as_str), Some("DIMM0"));
+        assert_eq!(dev.extra_props.get("BANK_LOCATOR").map(String::as_str), Some("BANK0"));
+    }
+
+    #[test]
+    fn uses_extended_size_when_size_is_sentinel() {
+        let mut data = type17(64, 64, 0x7FFF, 0x09, 0x1A, 3200);
+        data[0x1C..0x20].copy_from_slice(&32u32.to_le_bytes());
+        data.push(TYPE_END_OF_TABLE);
+        data.push(4);
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.extend_from_slice(b"\0\0");
+
+        let devices = devices_from_table(&data).unwrap();
+        assert_eq!(devices[0].capacity, Some(32 * 1024 * 1024));
+    }
+
+    #[test]
+    fn unknown_size_sentinel_reports_no_capacity() {
+        let mut data = type17(64, 64, 0xFFFF, 0x09, 0x1A, 3200);
+        data.push(TYPE_END_OF_TABLE);
+        data.push(4);
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.extend_from_slice(b"\0\0");
+
+        let devices = devices_from_table(&data).unwrap();
+        assert_eq!(devices[0].capacity, None);
+    }
+
+    #[test]
+    fn rejects_truncated_table() {
+        let data = vec![17, 0x22, 0, 0];
+        assert!(matches!(devices_from_table(&data), Err(Error::Smbios)));
+    }
+}